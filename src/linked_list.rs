@@ -1,23 +1,94 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+
+/// Error returned when an index passed to `LinkedList` is out of range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IndexOutOfRangeError {
+    pub index: usize,
+    pub len: usize,
+}
+
+impl fmt::Display for IndexOutOfRangeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "index {} out of range for list of length {}",
+            self.index, self.len
+        )
+    }
+}
+
+impl std::error::Error for IndexOutOfRangeError {}
+
+/// An opaque reference to a slot in a `Memory<T>` arena.
+///
+/// Pairs a slot index with the generation the slot had when the handle was
+/// created, so a handle into a slot that has since been freed and reused
+/// can be told apart from a handle to whatever occupies it now. `Handle`
+/// supports XOR the same way a plain index did, since XOR-ing each field
+/// independently is just as invertible as XOR-ing a single integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+
+impl Handle {
+    /// The null handle, representing "no node".
+    const NULL: Handle = Handle {
+        index: 0,
+        generation: 0,
+    };
+
+    fn is_null(self) -> bool {
+        self.index == 0
+    }
+}
+
+impl std::ops::BitXor for Handle {
+    type Output = Handle;
+
+    fn bitxor(self, rhs: Handle) -> Handle {
+        Handle {
+            index: self.index ^ rhs.index,
+            generation: self.generation ^ rhs.generation,
+        }
+    }
+}
+
+impl std::ops::BitXorAssign for Handle {
+    fn bitxor_assign(&mut self, rhs: Handle) {
+        *self = *self ^ rhs;
+    }
+}
+
 /// A node with simple payload.
 /// The pointer is the XOR of the prev and next ptr.
 #[derive(Debug, Clone)]
 pub struct Node<T> {
-    ptr: usize,
+    ptr: Handle,
     payload: T,
 }
 
 impl<T> Node<T> {
     pub fn new(payload: T) -> Self {
-        Node { ptr: 0, payload }
+        Node {
+            ptr: Handle::NULL,
+            payload,
+        }
     }
 }
 
 /// Simple memory management in a vector.
-/// 0 is a null pointer
-/// All other pointers are indexes into the vector + 1
+/// `Handle::NULL` is a null pointer.
+/// All other handles index into the vector + 1, tagged with the slot's
+/// current generation so stale handles into a recycled slot are rejected.
 #[derive(Debug, Clone)]
 pub struct Memory<T> {
-    slots: Vec<Option<Node<T>>>,
+    slots: Vec<(u32, Option<Node<T>>)>,
     free_slots: Vec<usize>,
 }
 
@@ -29,33 +100,113 @@ impl<T> Memory<T> {
         }
     }
 
-    /// Allocates a new node and returns its pointer
-    pub fn alloc(&mut self, payload: T) -> usize {
+    /// Creates an arena that pre-reserves storage for `capacity` nodes.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Memory {
+            slots: Vec::with_capacity(capacity),
+            free_slots: Vec::new(),
+        }
+    }
+
+    /// Reserves storage for at least `additional` more nodes beyond the
+    /// slots already freed for reuse.
+    pub fn reserve(&mut self, additional: usize) {
+        let spare = self.free_slots.len();
+        self.slots.reserve(additional.saturating_sub(spare));
+    }
+
+    /// Releases any storage `slots`/`free_slots` are holding beyond what
+    /// their current contents need.
+    ///
+    /// This only trims spare capacity; it does not reclaim the holes left
+    /// behind by freed nodes; see [`Memory::compact`] and
+    /// [`LinkedList::shrink_to_fit`] for that.
+    pub fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+        self.free_slots.shrink_to_fit();
+    }
+
+    /// Packs the live nodes into a dense prefix, preserving their relative
+    /// order, and drops the free list, reclaiming the holes left by past
+    /// removals. Returns the old -> new slot-index mapping (0-based) so a
+    /// caller holding handles into this arena can rewrite them.
+    fn compact(&mut self) -> HashMap<usize, usize> {
+        let mut mapping = HashMap::with_capacity(self.len());
+        let mut write = 0;
+        for read in 0..self.slots.len() {
+            if self.slots[read].1.is_some() {
+                if write != read {
+                    self.slots.swap(write, read);
+                }
+                mapping.insert(read, write);
+                write += 1;
+            }
+        }
+        self.slots.truncate(write);
+        self.free_slots.clear();
+        mapping
+    }
+
+    /// Allocates a new node and returns a handle to it.
+    pub fn alloc(&mut self, payload: T) -> Handle {
         let node = Node::new(payload);
         if let Some(slot_index) = self.free_slots.pop() {
-            self.slots[slot_index] = Some(node);
-            slot_index + 1
+            let generation = self.slots[slot_index].0;
+            self.slots[slot_index].1 = Some(node);
+            Handle {
+                index: slot_index + 1,
+                generation,
+            }
         } else {
-            self.slots.push(Some(node));
-            self.slots.len()
+            self.slots.push((0, Some(node)));
+            Handle {
+                index: self.slots.len(),
+                generation: 0,
+            }
         }
     }
 
-    /// Returns the node's payload and frees the node
-    pub fn remove(&mut self, ptr: usize) -> Option<T> {
-        self.free_slots.push(ptr - 1);
-        self.slots
-            .get_mut(ptr - 1)
-            .and_then(Option::take)
-            .map(|node| node.payload)
+    /// Returns the node's payload and frees the node, bumping its
+    /// generation so existing handles into this slot are invalidated.
+    pub fn remove(&mut self, handle: Handle) -> Option<T> {
+        if handle.is_null() {
+            return None;
+        }
+
+        let slot_index = handle.index - 1;
+        let slot = self.slots.get_mut(slot_index)?;
+        if slot.0 != handle.generation {
+            return None;
+        }
+
+        let payload = slot.1.take().map(|node| node.payload);
+        slot.0 = slot.0.wrapping_add(1);
+        self.free_slots.push(slot_index);
+        payload
     }
 
-    pub fn get_mut(&mut self, ptr: usize) -> Option<&mut Node<T>> {
-        if ptr == 0 {
+    pub fn get(&self, handle: Handle) -> Option<&Node<T>> {
+        if handle.is_null() {
             return None;
         }
 
-        self.slots.get_mut(ptr - 1).and_then(Option::as_mut)
+        let (generation, node) = self.slots.get(handle.index - 1)?;
+        if *generation != handle.generation {
+            return None;
+        }
+        node.as_ref()
+    }
+
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut Node<T>> {
+        if handle.is_null() {
+            return None;
+        }
+
+        let (generation, node) = self.slots.get_mut(handle.index - 1)?;
+        if *generation != handle.generation {
+            return None;
+        }
+        node.as_mut()
     }
 
     pub fn len(&self) -> usize {
@@ -65,20 +216,30 @@ impl<T> Memory<T> {
 
 #[derive(Debug, Clone)]
 pub struct LinkedList<T> {
-    head: usize,
-    tail: usize,
+    head: Handle,
+    tail: Handle,
     memory: Memory<T>,
 }
 
 impl<T> LinkedList<T> {
     pub fn new() -> Self {
         LinkedList {
-            head: 0,
-            tail: 0,
+            head: Handle::NULL,
+            tail: Handle::NULL,
             memory: Memory::new(),
         }
     }
 
+    /// Creates an empty list that pre-reserves storage for `capacity`
+    /// elements.
+    pub fn with_capacity(capacity: usize) -> Self {
+        LinkedList {
+            head: Handle::NULL,
+            tail: Handle::NULL,
+            memory: Memory::with_capacity(capacity),
+        }
+    }
+
     pub fn push_front(&mut self, payload: T) {
         let node_ptr = self.memory.alloc(payload);
 
@@ -138,7 +299,7 @@ impl<T> LinkedList<T> {
 
         if self.len() == 1 {
             // also need to reset the tail ptr
-            self.tail = 0;
+            self.tail = Handle::NULL;
         }
 
         self.memory.remove(head_node_ptr)
@@ -159,7 +320,7 @@ impl<T> LinkedList<T> {
 
         if self.len() == 1 {
             // also need to reset the head ptr
-            self.head = 0;
+            self.head = Handle::NULL;
         }
 
         self.memory.remove(tail_node_ptr)
@@ -168,6 +329,675 @@ impl<T> LinkedList<T> {
     pub fn len(&self) -> usize {
         self.memory.len()
     }
+
+    /// Compacts the arena: rewrites its live nodes into a dense prefix with
+    /// no holes left by past removals, then releases any spare storage
+    /// beyond that.
+    ///
+    /// Walks the chain once to decode each node's XOR pointer against its
+    /// old neighbors, compacts the arena, then rewrites each pointer (and
+    /// `head`/`tail`) using the old -> new index mapping `Memory::compact`
+    /// returns, all in place -- no nodes are popped or re-pushed.
+    pub fn shrink_to_fit(&mut self) {
+        let remap = |mapping: &HashMap<usize, usize>, handle: Handle| {
+            if handle.is_null() {
+                Handle::NULL
+            } else {
+                Handle {
+                    index: mapping[&(handle.index - 1)] + 1,
+                    generation: handle.generation,
+                }
+            }
+        };
+
+        let mut decoded = Vec::with_capacity(self.len());
+        let mut prev = Handle::NULL;
+        let mut current = self.head;
+        while let Some(node) = self.memory.get(current) {
+            let next = node.ptr ^ prev;
+            decoded.push((current, prev, next));
+            prev = current;
+            current = next;
+        }
+
+        let mapping = self.memory.compact();
+
+        for (handle, prev, next) in decoded {
+            let new_handle = remap(&mapping, handle);
+            let node = self.memory.get_mut(new_handle).unwrap();
+            node.ptr = remap(&mapping, prev) ^ remap(&mapping, next);
+        }
+
+        self.head = remap(&mapping, self.head);
+        self.tail = remap(&mapping, self.tail);
+        self.memory.shrink_to_fit();
+    }
+
+    /// Moves all elements of `other` onto the back of `self`, leaving
+    /// `other` empty.
+    ///
+    /// `other`'s slots are absorbed into `self`'s arena wholesale (a single
+    /// `Vec::append`) rather than re-homed one at a time, so no node goes
+    /// through `Memory::alloc` here. Reaching every absorbed node's XOR pointer once to
+    /// offset it into `self`'s index space is unavoidable -- XOR pointers
+    /// can't be decoded without a neighbor to XOR against -- but that pass
+    /// touches each of `other`'s nodes exactly once and does no allocation
+    /// or generation bookkeeping, unlike repeated `pop_front`/`push_back`.
+    /// Joining the two chains at the boundary is then just two XOR fixups.
+    pub fn append(&mut self, other: &mut LinkedList<T>) {
+        if other.len() == 0 {
+            return;
+        }
+
+        let offset = self.memory.slots.len();
+        let remap = |handle: Handle| {
+            if handle.is_null() {
+                Handle::NULL
+            } else {
+                Handle {
+                    index: handle.index + offset,
+                    generation: handle.generation,
+                }
+            }
+        };
+
+        // Rewrite `other`'s XOR pointers in place, offsetting each neighbor
+        // reference before it becomes undecodable by moving into `self`'s
+        // arena. `next_old` is pulled out before `node.ptr` is overwritten,
+        // so the walk still advances through `other`'s original encoding.
+        let mut prev_old = Handle::NULL;
+        let mut current_old = other.head;
+        while let Some(node) = other.memory.get_mut(current_old) {
+            let next_old = node.ptr ^ prev_old;
+            node.ptr = remap(prev_old) ^ remap(next_old);
+            prev_old = current_old;
+            current_old = next_old;
+        }
+
+        let new_other_head = remap(other.head);
+        let new_other_tail = remap(other.tail);
+
+        self.memory.slots.append(&mut other.memory.slots);
+        for free_slot in other.memory.free_slots.drain(..) {
+            self.memory.free_slots.push(free_slot + offset);
+        }
+
+        // Join the chains: self.tail <-> other.head.
+        if self.tail.is_null() {
+            self.head = new_other_head;
+        } else {
+            self.memory.get_mut(self.tail).unwrap().ptr ^= new_other_head;
+            self.memory.get_mut(new_other_head).unwrap().ptr ^= self.tail;
+        }
+        self.tail = new_other_tail;
+
+        other.head = Handle::NULL;
+        other.tail = Handle::NULL;
+    }
+
+    /// Splits the list into two at the given index, returning everything
+    /// from `at` onward as a new list and keeping `[0, at)` in `self`.
+    ///
+    /// Walks from whichever end is closer to `at`, relocating each node it
+    /// passes directly into the new list's arena and fixing its XOR pointer
+    /// in place, then cuts the two XOR fields at the boundary. The cost is
+    /// `O(min(at, len - at))`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > self.len()`.
+    pub fn split_off(&mut self, at: usize) -> LinkedList<T> {
+        let len = self.len();
+        assert!(at <= len, "split index out of bounds");
+
+        if at == 0 {
+            return std::mem::replace(self, LinkedList::new());
+        }
+        if at == len {
+            return LinkedList::new();
+        }
+
+        if at <= len - at {
+            // [0, at) is the shorter side: detach it into `front`, which then
+            // becomes `self`; the original arena, minus the detached nodes,
+            // is left holding [at, len) and is returned as-is.
+            let mut front = LinkedList::with_capacity(at);
+
+            let mut old_current = self.head;
+            let mut old_prev = Handle::NULL;
+            let mut new_prev = Handle::NULL;
+            for _ in 0..at {
+                let old_next = self.memory.get(old_current).unwrap().ptr ^ old_prev;
+                let payload = self.memory.remove(old_current).unwrap();
+
+                let new_current = front.memory.alloc(payload);
+                front.memory.get_mut(new_current).unwrap().ptr ^= new_prev;
+                if let Some(prev_node) = front.memory.get_mut(new_prev) {
+                    prev_node.ptr ^= new_current;
+                }
+                if front.head.is_null() {
+                    front.head = new_current;
+                }
+                front.tail = new_current;
+
+                new_prev = new_current;
+                old_prev = old_current;
+                old_current = old_next;
+            }
+
+            // `old_current` is the boundary node; cut its XOR link to the
+            // detached run and make it `self`'s new head.
+            self.head = old_current;
+            if let Some(new_head) = self.memory.get_mut(self.head) {
+                new_head.ptr ^= old_prev;
+            }
+
+            std::mem::replace(self, front)
+        } else {
+            // [at, len) is the shorter side: detach it into `back`, walking
+            // from `self.tail` backward, and return it; `self`'s arena keeps
+            // [0, at) untouched aside from the boundary cut.
+            let mut back = LinkedList::with_capacity(len - at);
+
+            let mut old_current = self.tail;
+            let mut old_next = Handle::NULL;
+            let mut new_next = Handle::NULL;
+            for _ in 0..(len - at) {
+                let old_prev = self.memory.get(old_current).unwrap().ptr ^ old_next;
+                let payload = self.memory.remove(old_current).unwrap();
+
+                let new_current = back.memory.alloc(payload);
+                back.memory.get_mut(new_current).unwrap().ptr ^= new_next;
+                if let Some(next_node) = back.memory.get_mut(new_next) {
+                    next_node.ptr ^= new_current;
+                }
+                if back.tail.is_null() {
+                    back.tail = new_current;
+                }
+                back.head = new_current;
+
+                new_next = new_current;
+                old_next = old_current;
+                old_current = old_prev;
+            }
+
+            // `old_current` is the boundary node; cut its XOR link to the
+            // detached run, making it `self`'s new tail.
+            self.tail = old_current;
+            if let Some(new_tail) = self.memory.get_mut(self.tail) {
+                new_tail.ptr ^= old_next;
+            }
+
+            back
+        }
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of
+    /// range.
+    ///
+    /// Walks from whichever end is closer to `index`, so the cost is
+    /// `O(min(index, len - index))`.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len() {
+            return None;
+        }
+        self.cursor_at(index).current()
+    }
+
+    /// Inserts `payload` at `index`, shifting everything at and after it one
+    /// position back.
+    ///
+    /// Walks from whichever end is closer to `index` and reuses the same
+    /// three-pointer re-link logic as [`CursorMut::insert_before`].
+    pub fn insert(&mut self, index: usize, payload: T) -> Result<(), IndexOutOfRangeError> {
+        let len = self.len();
+        if index > len {
+            return Err(IndexOutOfRangeError { index, len });
+        }
+
+        self.cursor_mut_at(index).insert_before(payload);
+        Ok(())
+    }
+
+    /// Removes and returns the element at `index`, shifting everything after
+    /// it one position forward.
+    ///
+    /// Walks from whichever end is closer to `index` and reuses the same
+    /// three-pointer re-link logic as [`CursorMut::remove_current`].
+    pub fn remove(&mut self, index: usize) -> Result<T, IndexOutOfRangeError> {
+        let len = self.len();
+        if index >= len {
+            return Err(IndexOutOfRangeError { index, len });
+        }
+
+        Ok(self.cursor_mut_at(index).remove_current().unwrap())
+    }
+
+    /// Returns a cursor positioned on the `index`-th element.
+    ///
+    /// Callers must ensure `index < len`: on an empty list the returned
+    /// cursor rests on the ghost element, and for a larger `index` the
+    /// `len - 1 - index` step count underflows.
+    fn cursor_at(&self, index: usize) -> Cursor<'_, T> {
+        let len = self.len();
+        if index <= len / 2 {
+            let mut cursor = self.cursor_front();
+            for _ in 0..index {
+                cursor.move_next();
+            }
+            cursor
+        } else {
+            let mut cursor = self.cursor_back();
+            for _ in 0..(len - 1 - index) {
+                cursor.move_prev();
+            }
+            cursor
+        }
+    }
+
+    /// Returns a mutable cursor positioned on the `index`-th element, or on
+    /// the ghost element when `index == len` (so inserting there appends).
+    ///
+    /// Callers must ensure `index <= len`.
+    fn cursor_mut_at(&mut self, index: usize) -> CursorMut<'_, T> {
+        let len = self.len();
+        if index == len {
+            if len == 0 {
+                return self.cursor_front_mut();
+            }
+            let mut cursor = self.cursor_back_mut();
+            cursor.move_next();
+            return cursor;
+        }
+
+        if index <= len / 2 {
+            let mut cursor = self.cursor_front_mut();
+            for _ in 0..index {
+                cursor.move_next();
+            }
+            cursor
+        } else {
+            let mut cursor = self.cursor_back_mut();
+            for _ in 0..(len - 1 - index) {
+                cursor.move_prev();
+            }
+            cursor
+        }
+    }
+
+    /// Returns a cursor positioned on the first element.
+    pub fn cursor_front(&self) -> Cursor<'_, T> {
+        Cursor {
+            list: self,
+            current: self.head,
+            prev: Handle::NULL,
+        }
+    }
+
+    /// Returns a mutable cursor positioned on the first element.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.head;
+        CursorMut {
+            list: self,
+            current,
+            prev: Handle::NULL,
+        }
+    }
+
+    /// Returns a cursor positioned on the last element.
+    pub fn cursor_back(&self) -> Cursor<'_, T> {
+        let prev = self.memory.get(self.tail).map_or(Handle::NULL, |node| node.ptr);
+        Cursor {
+            list: self,
+            current: self.tail,
+            prev,
+        }
+    }
+
+    /// Returns a mutable cursor positioned on the last element.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        let current = self.tail;
+        let prev = self.memory.get(current).map_or(Handle::NULL, |node| node.ptr);
+        CursorMut {
+            list: self,
+            current,
+            prev,
+        }
+    }
+
+    /// Returns a borrowing iterator over the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            memory: &self.memory,
+            front_ptr: self.head,
+            front_prev: Handle::NULL,
+            back_ptr: self.tail,
+            back_prev: Handle::NULL,
+            remaining: self.len(),
+        }
+    }
+
+    /// Returns a mutable borrowing iterator over the elements, front to back.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        let front_ptr = self.head;
+        let back_ptr = self.tail;
+        let remaining = self.len();
+        IterMut {
+            memory: &mut self.memory,
+            front_ptr,
+            front_prev: Handle::NULL,
+            back_ptr,
+            back_prev: Handle::NULL,
+            remaining,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A borrowing iterator over the elements of a `LinkedList`.
+///
+/// Walks the XOR chain from both ends at once, meeting in the middle; unlike
+/// [`LinkedListIter`], it leaves the list intact. See [`Cursor`] for how the
+/// `prev`/`ptr` XOR trick recovers neighbors.
+pub struct Iter<'a, T> {
+    memory: &'a Memory<T>,
+    front_ptr: Handle,
+    front_prev: Handle,
+    back_ptr: Handle,
+    back_prev: Handle,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.memory.get(self.front_ptr)?;
+        let nxt = node.ptr ^ self.front_prev;
+        self.front_prev = self.front_ptr;
+        self.front_ptr = nxt;
+        Some(&node.payload)
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Iter<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        let node = self.memory.get(self.back_ptr)?;
+        let prv = node.ptr ^ self.back_prev;
+        self.back_prev = self.back_ptr;
+        self.back_ptr = prv;
+        Some(&node.payload)
+    }
+}
+
+/// A mutable borrowing iterator over the elements of a `LinkedList`.
+///
+/// See [`Iter`] for the traversal scheme. Because the front and back walks
+/// only ever yield disjoint, not-yet-visited slots (tracked by `remaining`),
+/// handing out two live `&mut T` at once is sound even though they are
+/// reached through a shared `*mut Memory<T>`.
+pub struct IterMut<'a, T> {
+    memory: *mut Memory<T>,
+    front_ptr: Handle,
+    front_prev: Handle,
+    back_ptr: Handle,
+    back_prev: Handle,
+    remaining: usize,
+    _marker: PhantomData<&'a mut Memory<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: `remaining` tracks how many not-yet-visited slots are left
+        // between the front and back walks, so this slot is disjoint from
+        // every reference already handed out or yet to come.
+        let node = unsafe { (*self.memory).get_mut(self.front_ptr)? };
+        let nxt = node.ptr ^ self.front_prev;
+        self.front_prev = self.front_ptr;
+        self.front_ptr = nxt;
+        Some(unsafe { &mut *(&mut node.payload as *mut T) })
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for IterMut<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+
+        // SAFETY: see `next`; the back walk only ever touches slots the
+        // front walk has not reached yet.
+        let node = unsafe { (*self.memory).get_mut(self.back_ptr)? };
+        let prv = node.ptr ^ self.back_prev;
+        self.back_prev = self.back_ptr;
+        self.back_ptr = prv;
+        Some(unsafe { &mut *(&mut node.payload as *mut T) })
+    }
+}
+
+/// A cursor over a `LinkedList` that can only read the list it traverses.
+///
+/// A cursor always rests between two elements, but is represented by the
+/// element it currently points at. It additionally keeps track of the
+/// neighbor it arrived from (`prev`), which is needed to recover the other
+/// neighbor from the XOR-encoded `ptr` field. Moving past either end of the
+/// list lands the cursor on the "ghost" element (`current == Handle::NULL`),
+/// from which the next move wraps around to the opposite end.
+pub struct Cursor<'a, T> {
+    list: &'a LinkedList<T>,
+    current: Handle,
+    prev: Handle,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Returns a reference to the element the cursor is currently pointing at.
+    pub fn current(&self) -> Option<&'a T> {
+        self.list.memory.get(self.current).map(|node| &node.payload)
+    }
+
+    /// Moves the cursor to the next element.
+    pub fn move_next(&mut self) {
+        match self.list.memory.get(self.current) {
+            Some(node) => {
+                let next = node.ptr ^ self.prev;
+                self.prev = self.current;
+                self.current = next;
+            }
+            None => {
+                // Past the end: wrap around to the front.
+                self.current = self.list.head;
+                self.prev = Handle::NULL;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element.
+    pub fn move_prev(&mut self) {
+        if self.current.is_null() {
+            // Past the front: wrap around to the back.
+            self.current = self.list.tail;
+            self.prev = self
+                .list
+                .memory
+                .get(self.current)
+                .map_or(Handle::NULL, |node| node.ptr);
+            return;
+        }
+
+        if self.prev.is_null() {
+            // On the head: move past it, onto the ghost.
+            self.prev = self.current;
+            self.current = Handle::NULL;
+            return;
+        }
+
+        let prev_node = self.list.memory.get(self.prev).unwrap();
+        let new_prev = prev_node.ptr ^ self.current;
+        self.current = self.prev;
+        self.prev = new_prev;
+    }
+}
+
+/// A cursor over a `LinkedList` that can mutate the list it traverses.
+///
+/// See [`Cursor`] for how positions and movement are represented.
+pub struct CursorMut<'a, T> {
+    list: &'a mut LinkedList<T>,
+    current: Handle,
+    prev: Handle,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Returns a reference to the element the cursor is currently pointing at.
+    pub fn current(&self) -> Option<&T> {
+        self.list.memory.get(self.current).map(|node| &node.payload)
+    }
+
+    /// Returns a mutable reference to the element the cursor is currently pointing at.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.list
+            .memory
+            .get_mut(self.current)
+            .map(|node| &mut node.payload)
+    }
+
+    /// Moves the cursor to the next element.
+    pub fn move_next(&mut self) {
+        match self.list.memory.get(self.current) {
+            Some(node) => {
+                let next = node.ptr ^ self.prev;
+                self.prev = self.current;
+                self.current = next;
+            }
+            None => {
+                self.current = self.list.head;
+                self.prev = Handle::NULL;
+            }
+        }
+    }
+
+    /// Moves the cursor to the previous element.
+    pub fn move_prev(&mut self) {
+        if self.current.is_null() {
+            // Past the front: wrap around to the back.
+            self.current = self.list.tail;
+            self.prev = self
+                .list
+                .memory
+                .get(self.current)
+                .map_or(Handle::NULL, |node| node.ptr);
+            return;
+        }
+
+        if self.prev.is_null() {
+            // On the head: move past it, onto the ghost.
+            self.prev = self.current;
+            self.current = Handle::NULL;
+            return;
+        }
+
+        let prev_node = self.list.memory.get(self.prev).unwrap();
+        let new_prev = prev_node.ptr ^ self.current;
+        self.current = self.prev;
+        self.prev = new_prev;
+    }
+
+    /// Inserts a new element directly after the cursor's current position.
+    ///
+    /// If the cursor is on the ghost element, the new element is inserted at
+    /// the front of the list.
+    pub fn insert_after(&mut self, payload: T) {
+        let current = self.current;
+        if current.is_null() {
+            self.list.push_front(payload);
+            return;
+        }
+
+        let node_ptr = self.list.memory.alloc(payload);
+        let next = self.list.memory.get(current).unwrap().ptr ^ self.prev;
+
+        self.list.memory.get_mut(current).unwrap().ptr ^= next ^ node_ptr;
+        self.list.memory.get_mut(node_ptr).unwrap().ptr = current ^ next;
+
+        if !next.is_null() {
+            self.list.memory.get_mut(next).unwrap().ptr ^= current ^ node_ptr;
+        } else {
+            self.list.tail = node_ptr;
+        }
+    }
+
+    /// Inserts a new element directly before the cursor's current position.
+    ///
+    /// If the cursor is on the ghost element, the new element is inserted at
+    /// the back of the list.
+    pub fn insert_before(&mut self, payload: T) {
+        let current = self.current;
+        if current.is_null() {
+            self.list.push_back(payload);
+            self.prev = self.list.tail;
+            return;
+        }
+
+        let node_ptr = self.list.memory.alloc(payload);
+        let prev = self.prev;
+
+        self.list.memory.get_mut(current).unwrap().ptr ^= prev ^ node_ptr;
+        self.list.memory.get_mut(node_ptr).unwrap().ptr = prev ^ current;
+
+        if !prev.is_null() {
+            self.list.memory.get_mut(prev).unwrap().ptr ^= current ^ node_ptr;
+        } else {
+            self.list.head = node_ptr;
+        }
+
+        self.prev = node_ptr;
+    }
+
+    /// Removes the element at the cursor's current position, returning its
+    /// payload and advancing the cursor to the element that followed it.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current;
+        let node = self.list.memory.get(current)?;
+        let prev = self.prev;
+        let next = node.ptr ^ prev;
+
+        if !prev.is_null() {
+            let prev_node = self.list.memory.get_mut(prev).unwrap();
+            prev_node.ptr ^= current;
+            prev_node.ptr ^= next;
+        } else {
+            self.list.head = next;
+        }
+
+        if !next.is_null() {
+            let next_node = self.list.memory.get_mut(next).unwrap();
+            next_node.ptr ^= current;
+            next_node.ptr ^= prev;
+        } else {
+            self.list.tail = prev;
+        }
+
+        self.current = next;
+        self.list.memory.remove(current)
+    }
 }
 
 impl<T> IntoIterator for LinkedList<T> {
@@ -191,6 +1021,51 @@ impl<T> Iterator for LinkedListIter<T> {
     }
 }
 
+impl<T> FromIterator<T> for LinkedList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = LinkedList::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T> Extend<T> for LinkedList<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for payload in iter {
+            self.push_back(payload);
+        }
+    }
+}
+
+impl<T: PartialEq> PartialEq for LinkedList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for LinkedList<T> {}
+
+impl<T: Hash> Hash for LinkedList<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        for item in self.iter() {
+            item.hash(state);
+        }
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for LinkedList<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.iter().partial_cmp(other.iter())
+    }
+}
+
+impl<T: Ord> Ord for LinkedList<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.iter().cmp(other.iter())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -307,4 +1182,428 @@ mod tests {
 
         assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![7, 6, 3]);
     }
+
+    #[test]
+    fn cursor_mut_insert_and_remove_mid_list() {
+        let mut list = LinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        // Walk to the element at index 2 (value 2).
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&2));
+
+        cursor.insert_before(100);
+        cursor.insert_after(200);
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&200));
+
+        assert_eq!(
+            list.clone().into_iter().collect::<Vec<_>>(),
+            vec![0, 1, 100, 200, 3, 4]
+        );
+    }
+
+    #[test]
+    fn cursor_forward_and_backward_traversal() {
+        let mut list = LinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front();
+        let mut forward = Vec::new();
+        for _ in 0..list.len() {
+            forward.push(*cursor.current().unwrap());
+            cursor.move_next();
+        }
+        assert_eq!(forward, vec![0, 1, 2, 3, 4]);
+
+        let mut cursor = list.cursor_back();
+        let mut backward = Vec::new();
+        for _ in 0..list.len() {
+            backward.push(*cursor.current().unwrap());
+            cursor.move_prev();
+        }
+        assert_eq!(backward, vec![4, 3, 2, 1, 0]);
+    }
+
+    #[test]
+    fn cursor_move_prev_from_head_lands_on_ghost_before_wrapping() {
+        let mut list = LinkedList::new();
+        for i in 0..3 {
+            list.push_back(i);
+        }
+
+        let mut cursor = list.cursor_front();
+        assert_eq!(cursor.current(), Some(&0));
+
+        cursor.move_prev();
+        assert_eq!(cursor.current(), None);
+
+        // Only the *next* move_prev wraps around to the back.
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&2));
+    }
+
+    #[test]
+    fn cursor_move_prev_mirrors_move_next_and_terminates() {
+        let mut list = LinkedList::new();
+        for i in 0..3 {
+            list.push_back(i);
+        }
+
+        let mut forward_cursor = list.cursor_front();
+        let mut forward = Vec::new();
+        while let Some(value) = forward_cursor.current() {
+            forward.push(*value);
+            forward_cursor.move_next();
+        }
+        assert_eq!(forward, vec![0, 1, 2]);
+
+        let mut backward_cursor = list.cursor_back();
+        let mut backward = Vec::new();
+        while let Some(value) = backward_cursor.current() {
+            backward.push(*value);
+            backward_cursor.move_prev();
+        }
+        assert_eq!(backward, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn iter_does_not_consume_the_list() {
+        let mut list = LinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![&0, &1, &2, &3, &4]);
+        assert_eq!(list.iter().rev().collect::<Vec<_>>(), vec![&4, &3, &2, &1, &0]);
+
+        // The list is still intact after borrowing iteration.
+        assert_eq!(list.len(), 5);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn iter_mut_allows_mutation_in_place() {
+        let mut list = LinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        for value in list.iter_mut() {
+            *value *= 10;
+        }
+
+        assert_eq!(
+            list.into_iter().collect::<Vec<_>>(),
+            vec![0, 10, 20, 30, 40]
+        );
+    }
+
+    #[test]
+    fn iter_meets_in_the_middle_from_both_ends() {
+        let mut list = LinkedList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&0));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn append_moves_all_elements_and_empties_other() {
+        let mut a = LinkedList::new();
+        for i in 0..3 {
+            a.push_back(i);
+        }
+        let mut b = LinkedList::new();
+        for i in 3..6 {
+            b.push_back(i);
+        }
+
+        a.append(&mut b);
+
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5]);
+        assert_eq!(b.len(), 0);
+        assert_eq!(b.into_iter().collect::<Vec<_>>(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn append_to_empty_list() {
+        let mut a = LinkedList::new();
+        let mut b = LinkedList::new();
+        for i in 0..3 {
+            b.push_back(i);
+        }
+
+        a.append(&mut b);
+
+        assert_eq!(a.into_iter().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(b.len(), 0);
+    }
+
+    #[test]
+    fn append_remaps_reused_slots_and_non_zero_generations() {
+        let mut a = LinkedList::new();
+        for i in 0..4 {
+            a.push_back(i);
+        }
+        // Leave a hole in `a`'s arena and bump a generation, so the arena `b`
+        // is appended into already has history for the remap to get wrong.
+        a.pop_front();
+
+        let mut b = LinkedList::new();
+        for i in 10..13 {
+            b.push_back(i);
+        }
+        // Same for `b`: a freed-and-reused slot with its own generation.
+        b.pop_front();
+        b.push_front(10);
+
+        a.append(&mut b);
+
+        assert_eq!(
+            a.into_iter().collect::<Vec<_>>(),
+            vec![1, 2, 3, 10, 11, 12]
+        );
+        assert_eq!(b.len(), 0);
+    }
+
+    #[test]
+    fn split_off_middle() {
+        let mut list = LinkedList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+
+        let tail = list.split_off(2);
+
+        assert_eq!(list.clone().into_iter().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(tail.into_iter().collect::<Vec<_>>(), vec![2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn split_off_detaches_shorter_side_from_either_end() {
+        let mut list = LinkedList::new();
+        for i in 0..6 {
+            list.push_back(i);
+        }
+        // Free and reuse a slot, bumping its generation, so the split has to
+        // carry a node whose handle has a non-zero generation.
+        list.pop_front();
+        list.push_front(0);
+
+        // Splitting closer to the back detaches the shorter tail run.
+        let tail = list.split_off(4);
+        assert_eq!(list.clone().into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+        assert_eq!(tail.clone().into_iter().collect::<Vec<_>>(), vec![4, 5]);
+
+        // Splitting closer to the front detaches the shorter front run,
+        // which becomes `self`; the rest is returned.
+        let rest = list.split_off(1);
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0]);
+        assert_eq!(rest.into_iter().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn split_off_at_zero_and_at_len() {
+        let mut list = LinkedList::new();
+        for i in 0..4 {
+            list.push_back(i);
+        }
+
+        let mut all = list.split_off(0);
+        assert_eq!(list.len(), 0);
+        assert_eq!(all.clone().into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+
+        let empty = all.split_off(all.len());
+        assert_eq!(empty.len(), 0);
+        assert_eq!(all.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_off_out_of_bounds_panics() {
+        let mut list = LinkedList::new();
+        list.push_back(1);
+        list.split_off(2);
+    }
+
+    #[test]
+    fn get_returns_elements_from_either_end() {
+        let mut list = LinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        for i in 0..5 {
+            assert_eq!(list.get(i), Some(&i));
+        }
+        assert_eq!(list.get(5), None);
+    }
+
+    #[test]
+    fn insert_shifts_elements_back() {
+        let mut list = LinkedList::new();
+        for i in [0, 1, 3, 4] {
+            list.push_back(i);
+        }
+
+        assert_eq!(list.insert(2, 2), Ok(()));
+        assert_eq!(
+            list.clone().into_iter().collect::<Vec<_>>(),
+            vec![0, 1, 2, 3, 4]
+        );
+
+        assert_eq!(list.insert(0, -1), Ok(()));
+        assert_eq!(list.insert(6, 5), Ok(()));
+        assert_eq!(
+            list.clone().into_iter().collect::<Vec<_>>(),
+            vec![-1, 0, 1, 2, 3, 4, 5]
+        );
+
+        assert_eq!(
+            list.insert(100, 99),
+            Err(IndexOutOfRangeError { index: 100, len: 7 })
+        );
+    }
+
+    #[test]
+    fn remove_shifts_elements_forward() {
+        let mut list = LinkedList::new();
+        for i in 0..5 {
+            list.push_back(i);
+        }
+
+        assert_eq!(list.remove(2), Ok(2));
+        assert_eq!(list.clone().into_iter().collect::<Vec<_>>(), vec![0, 1, 3, 4]);
+
+        assert_eq!(
+            list.remove(10),
+            Err(IndexOutOfRangeError { index: 10, len: 4 })
+        );
+    }
+
+    #[test]
+    fn stale_handle_is_rejected_after_slot_reuse() {
+        let mut memory = Memory::new();
+        let first = memory.alloc(1);
+
+        assert_eq!(memory.remove(first), Some(1));
+
+        // Reuses the freed slot, whose generation `remove` bumped.
+        let second = memory.alloc(2);
+        assert_eq!(first.index, second.index);
+        assert_ne!(first.generation, second.generation);
+
+        assert!(memory.get(first).is_none());
+        assert!(memory.get_mut(first).is_none());
+        assert_eq!(memory.remove(first), None);
+
+        assert_eq!(memory.get(second).map(|node| &node.payload), Some(&2));
+    }
+
+    #[test]
+    fn shrink_to_fit_reclaims_holes_and_preserves_order() {
+        let mut list = LinkedList::new();
+        for i in 0..10 {
+            list.push_back(i);
+        }
+
+        // Churn through pop/push cycles to leave holes in the arena.
+        for _ in 0..5 {
+            list.pop_front();
+        }
+        for i in 10..15 {
+            list.push_back(i);
+        }
+        for _ in 0..5 {
+            list.pop_back();
+        }
+
+        let before = list.clone().into_iter().collect::<Vec<_>>();
+        list.shrink_to_fit();
+        let after = list.clone().into_iter().collect::<Vec<_>>();
+
+        assert_eq!(before, after);
+        assert_eq!(after, vec![5, 6, 7, 8, 9]);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn with_capacity_starts_empty_and_usable() {
+        let mut list = LinkedList::with_capacity(8);
+        assert_eq!(list.len(), 0);
+
+        for i in 0..8 {
+            list.push_back(i);
+        }
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), (0..8).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn from_iterator_and_extend_push_to_the_back() {
+        let mut list: LinkedList<i32> = (0..5).collect();
+        list.extend([5, 6]);
+
+        assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn equality_compares_elements_not_representation() {
+        let a: LinkedList<i32> = (0..3).collect();
+        let mut b: LinkedList<i32> = LinkedList::new();
+        b.push_back(0);
+        b.push_back(1);
+        b.push_back(2);
+        b.pop_front();
+        b.push_front(0);
+
+        assert_eq!(a, b);
+
+        let c: LinkedList<i32> = (0..4).collect();
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn ordering_is_lexicographic_with_length_fallback() {
+        let a: LinkedList<i32> = vec![1, 2, 3].into_iter().collect();
+        let b: LinkedList<i32> = vec![1, 2, 4].into_iter().collect();
+        let prefix: LinkedList<i32> = vec![1, 2].into_iter().collect();
+
+        assert!(a < b);
+        assert!(prefix < a);
+        assert_eq!(a.cmp(&a.clone()), std::cmp::Ordering::Equal);
+    }
+
+    #[test]
+    fn hash_matches_for_equal_lists() {
+        use std::collections::hash_map::DefaultHasher;
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a: LinkedList<i32> = (0..5).collect();
+        let b: LinkedList<i32> = (0..5).collect();
+        let c: LinkedList<i32> = (0..6).collect();
+
+        assert_eq!(hash_of(&a), hash_of(&b));
+        assert_ne!(hash_of(&a), hash_of(&c));
+    }
 }
@@ -1,6 +1,4 @@
-use linked_list::LinkedList;
-
-mod linked_list;
+use doubly_linked_list::linked_list::LinkedList;
 
 fn main() {
     let mut list = LinkedList::new();